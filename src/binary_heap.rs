@@ -0,0 +1,182 @@
+use core::cmp::Ordering;
+
+use crate::vector::{Vector, VectorError};
+
+/// A binary max-heap backed by `Vector<T>`, keyed by a caller-supplied
+/// comparator. `BinaryHeap<T>` is the `Ord`-keyed specialization built on
+/// top of this, the same way `Vector::sort` is built on `Vector::sort_by`.
+pub struct BinaryHeapBy<T, F: Fn(&T, &T) -> Ordering + Copy> {
+    data: Vector<T>,
+    cmp: F,
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering + Copy> BinaryHeapBy<T, F> {
+    pub fn new(cmp: F) -> Self {
+        Self {
+            data: Vector::new(),
+            cmp,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.data.as_slice().first()
+    }
+
+    /// Heapifies an existing `Vector` in O(n) by sifting down from the last
+    /// parent (`len / 2 - 1`) back to the root.
+    pub fn from_vector(data: Vector<T>, cmp: F) -> Self {
+        let len = data.len();
+        let mut heap = Self { data, cmp };
+        for i in (0..len / 2).rev() {
+            heap.sift_down(i);
+        }
+        heap
+    }
+    pub fn push(&mut self, value: T) -> Result<(), VectorError> {
+        self.data.push(value)?;
+        self.sift_up(self.data.len() - 1);
+        Ok(())
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.data.len().checked_sub(1)?;
+        self.data.swap(0, last);
+        let popped = self.data.pop();
+        if self.data.len() > 0 {
+            self.sift_down(0);
+        }
+        popped
+    }
+    /// Repeatedly pops (largest first) and reverses the result into
+    /// ascending order.
+    pub fn into_sorted_vector(mut self) -> Vector<T> {
+        let mut sorted = Vector::new();
+        while let Some(value) = self.pop() {
+            sorted.push(value).unwrap();
+        }
+        sorted.reverse();
+        sorted
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if (self.cmp)(&self.data[index], &self.data[parent]) == Ordering::Greater {
+                self.data.swap(index, parent);
+                index = parent;
+            } else {
+                break;
+            }
+        }
+    }
+    fn sift_down(&mut self, mut index: usize) {
+        let len = self.data.len();
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut largest = index;
+            if left < len && (self.cmp)(&self.data[left], &self.data[largest]) == Ordering::Greater
+            {
+                largest = left;
+            }
+            if right < len
+                && (self.cmp)(&self.data[right], &self.data[largest]) == Ordering::Greater
+            {
+                largest = right;
+            }
+            if largest == index {
+                break;
+            }
+            self.data.swap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+/// A binary max-heap priority queue backed by `Vector<T>`.
+pub struct BinaryHeap<T: Ord> {
+    inner: BinaryHeapBy<T, fn(&T, &T) -> Ordering>,
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            inner: BinaryHeapBy::new(Ord::cmp),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.peek()
+    }
+    pub fn from_vector(data: Vector<T>) -> Self {
+        Self {
+            inner: BinaryHeapBy::from_vector(data, Ord::cmp),
+        }
+    }
+    pub fn push(&mut self, value: T) -> Result<(), VectorError> {
+        self.inner.push(value)
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+    pub fn into_sorted_vector(self) -> Vector<T> {
+        self.inner.into_sorted_vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_heap_push_pop_max_order() {
+        let mut heap = BinaryHeap::new();
+        for i in [3, 1, 4, 1, 5, 9, 2, 6] {
+            heap.push(i).unwrap();
+        }
+        let mut popped = Vector::new();
+        while let Some(v) = heap.pop() {
+            popped.push(v).unwrap();
+        }
+        assert_eq!(popped.as_slice(), &[9, 6, 5, 4, 3, 2, 1, 1]);
+    }
+    #[test]
+    fn test_binary_heap_peek() {
+        let mut heap = BinaryHeap::new();
+        heap.push(5).unwrap();
+        heap.push(10).unwrap();
+        heap.push(2).unwrap();
+        assert_eq!(heap.peek(), Some(&10));
+    }
+    #[test]
+    fn test_binary_heap_from_vector_into_sorted_vector() {
+        let vec = Vector::try_from_iter([5, 3, 8, 1, 9, 2]).unwrap();
+        let heap = BinaryHeap::from_vector(vec);
+        let sorted = heap.into_sorted_vector();
+        assert_eq!(sorted.as_slice(), &[1, 2, 3, 5, 8, 9]);
+    }
+    #[test]
+    fn test_binary_heap_by_min_heap() {
+        let mut heap = BinaryHeapBy::new(|a: &i32, b: &i32| b.cmp(a));
+        for i in [5, 1, 4, 2, 3] {
+            heap.push(i).unwrap();
+        }
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+    }
+}
@@ -0,0 +1,278 @@
+extern crate alloc;
+use alloc::alloc::{AllocError, Allocator, Global, Layout, LayoutError};
+#[cfg(feature = "std")]
+use core::fmt::Display;
+use core::marker::{PhantomData, Unsize};
+use core::ptr::{self, NonNull, Pointee};
+
+use crate::vector::{Vector, VectorError};
+
+#[derive(Debug)]
+pub enum DynVecError {
+    LayoutError(LayoutError),
+    AllocError(AllocError),
+}
+
+#[cfg(feature = "std")]
+impl Display for DynVecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DynVecError::LayoutError(e) => write!(f, "Layout error: {}", e),
+            DynVecError::AllocError(e) => write!(f, "Allocation error: {}", e),
+        }
+    }
+}
+impl From<LayoutError> for DynVecError {
+    fn from(e: LayoutError) -> Self {
+        DynVecError::LayoutError(e)
+    }
+}
+impl From<AllocError> for DynVecError {
+    fn from(e: AllocError) -> Self {
+        DynVecError::AllocError(e)
+    }
+}
+impl From<VectorError> for DynVecError {
+    fn from(e: VectorError) -> Self {
+        match e {
+            VectorError::LayoutError(e) => DynVecError::LayoutError(e),
+            VectorError::AllocError(e) => DynVecError::AllocError(e),
+        }
+    }
+}
+
+/// Where one element's bytes live in `DynVec::buffer`, plus its fat-pointer metadata.
+struct Entry<T: ?Sized> {
+    offset: usize,
+    metadata: <T as Pointee>::Metadata,
+}
+
+/// A contiguous container for unsized trait objects (e.g. `dyn Trait`).
+pub struct DynVec<T: ?Sized> {
+    buffer: NonNull<u8>,
+    byte_capacity: usize,
+    byte_align: usize,
+    cursor: usize,
+    entries: Vector<Entry<T>>,
+    allocator: Global,
+    _marker: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for DynVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> DynVec<T> {
+    pub fn new() -> Self {
+        Self {
+            buffer: NonNull::dangling(),
+            byte_capacity: 0,
+            byte_align: core::mem::align_of::<usize>(),
+            cursor: 0,
+            entries: Vector::new(),
+            allocator: Global,
+            _marker: PhantomData,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn byte_layout(capacity: usize, align: usize) -> Result<Layout, DynVecError> {
+        Layout::from_size_align(capacity, align).map_err(Into::into)
+    }
+    /// Ensures room for `additional` more bytes at alignment `align`.
+    fn reserve_bytes(&mut self, additional: usize, align: usize) -> Result<(), DynVecError> {
+        let needs_more_space = self.cursor + additional > self.byte_capacity;
+        let needs_bigger_align = align > self.byte_align;
+        if !needs_more_space && !needs_bigger_align {
+            return Ok(());
+        }
+        let new_capacity = (self.byte_capacity + additional).next_power_of_two();
+        let new_align = self.byte_align.max(align);
+        let new_layout = Self::byte_layout(new_capacity, new_align)?;
+        let new_buffer = unsafe {
+            if self.byte_capacity == 0 {
+                self.allocator.allocate(new_layout)?.cast()
+            } else if needs_bigger_align {
+                let old_layout = Self::byte_layout(self.byte_capacity, self.byte_align)?;
+                let fresh: NonNull<u8> = self.allocator.allocate(new_layout)?.cast();
+                core::ptr::copy_nonoverlapping(self.buffer.as_ptr(), fresh.as_ptr(), self.cursor);
+                self.allocator.deallocate(self.buffer, old_layout);
+                fresh
+            } else {
+                let old_layout = Self::byte_layout(self.byte_capacity, self.byte_align)?;
+                self.allocator
+                    .grow(self.buffer, old_layout, new_layout)?
+                    .cast()
+            }
+        };
+        self.buffer = new_buffer;
+        self.byte_capacity = new_capacity;
+        self.byte_align = new_align;
+        Ok(())
+    }
+
+    /// Pushes `value`, coerced to `T` via `Unsize`, onto the end of the buffer.
+    pub fn push<U>(&mut self, value: U) -> Result<(), DynVecError>
+    where
+        U: Unsize<T>,
+    {
+        let layout = Layout::for_value(&value);
+        let metadata = ptr::metadata(&value as &T);
+        let aligned_offset = (self.cursor + layout.align() - 1) & !(layout.align() - 1);
+        self.reserve_bytes(
+            (aligned_offset - self.cursor) + layout.size(),
+            layout.align(),
+        )?;
+        self.entries.reserve(1)?;
+        unsafe {
+            let dst = self.buffer.as_ptr().add(aligned_offset);
+            ptr::copy_nonoverlapping((&value as *const U).cast::<u8>(), dst, layout.size());
+        }
+        core::mem::forget(value);
+        self.entries
+            .push(Entry {
+                offset: aligned_offset,
+                metadata,
+            })
+            .expect("entries capacity reserved above");
+        self.cursor = aligned_offset + layout.size();
+        Ok(())
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let entry = self.entries.get(index)?;
+        let ptr = unsafe { self.buffer.as_ptr().add(entry.offset) };
+        Some(unsafe { &*core::ptr::from_raw_parts(ptr.cast::<()>(), entry.metadata) })
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let entry = self.entries.get(index)?;
+        let offset = entry.offset;
+        let metadata = entry.metadata;
+        let ptr = unsafe { self.buffer.as_ptr().add(offset) };
+        Some(unsafe { &mut *core::ptr::from_raw_parts_mut(ptr.cast::<()>(), metadata) })
+    }
+
+    pub fn iter(&self) -> DynVecIter<'_, T> {
+        DynVecIter {
+            dyn_vec: self,
+            index: 0,
+        }
+    }
+    pub fn iter_mut(&mut self) -> DynVecIterMut<'_, T> {
+        DynVecIterMut {
+            buffer: self.buffer,
+            entries: self.entries.as_slice().iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for DynVec<T> {
+    fn drop(&mut self) {
+        for entry in self.entries.iter() {
+            unsafe {
+                let ptr = self.buffer.as_ptr().add(entry.offset);
+                let fat: *mut T = core::ptr::from_raw_parts_mut(ptr.cast::<()>(), entry.metadata);
+                core::ptr::drop_in_place(fat);
+            }
+        }
+        if self.byte_capacity > 0 {
+            unsafe {
+                let layout =
+                    Self::byte_layout(self.byte_capacity, self.byte_align).unwrap_unchecked();
+                self.allocator.deallocate(self.buffer, layout);
+            }
+        }
+    }
+}
+
+pub struct DynVecIter<'a, T: ?Sized> {
+    dyn_vec: &'a DynVec<T>,
+    index: usize,
+}
+impl<'a, T: ?Sized> Iterator for DynVecIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.dyn_vec.get(self.index)?;
+        self.index += 1;
+        Some(item)
+    }
+}
+
+pub struct DynVecIterMut<'a, T: ?Sized> {
+    buffer: NonNull<u8>,
+    entries: core::slice::Iter<'a, Entry<T>>,
+    _marker: PhantomData<&'a mut T>,
+}
+impl<'a, T: ?Sized> Iterator for DynVecIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.next()?;
+        let ptr = unsafe { self.buffer.as_ptr().add(entry.offset) };
+        Some(unsafe { &mut *core::ptr::from_raw_parts_mut(ptr.cast::<()>(), entry.metadata) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    trait Greet {
+        fn greet(&self) -> alloc::string::String;
+    }
+    struct Hello;
+    impl Greet for Hello {
+        fn greet(&self) -> alloc::string::String {
+            "hello".into()
+        }
+    }
+    struct Name(&'static str);
+    impl Greet for Name {
+        fn greet(&self) -> alloc::string::String {
+            alloc::format!("hi, {}", self.0)
+        }
+    }
+
+    #[repr(align(64))]
+    struct Overaligned(u64);
+    impl Greet for Overaligned {
+        fn greet(&self) -> alloc::string::String {
+            alloc::format!("overaligned {}", self.0)
+        }
+    }
+
+    #[test]
+    fn test_dyn_vec_push_respects_overalignment() {
+        let mut vec: DynVec<dyn Greet> = DynVec::new();
+        vec.push(Hello).unwrap();
+        vec.push(Overaligned(7)).unwrap();
+        assert_eq!(vec.get(1).unwrap().greet(), "overaligned 7");
+        let ptr = vec.get(1).unwrap() as *const dyn Greet;
+        assert_eq!(ptr.cast::<()>().align_offset(64), 0);
+    }
+
+    #[test]
+    fn test_dyn_vec_push_and_get() {
+        let mut vec: DynVec<dyn Greet> = DynVec::new();
+        vec.push(Hello).unwrap();
+        vec.push(Name("world")).unwrap();
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.get(0).unwrap().greet(), "hello");
+        assert_eq!(vec.get(1).unwrap().greet(), "hi, world");
+    }
+    #[test]
+    fn test_dyn_vec_iter() {
+        let mut vec: DynVec<dyn Greet> = DynVec::new();
+        vec.push(Hello).unwrap();
+        vec.push(Name("there")).unwrap();
+        let greetings: alloc::vec::Vec<_> = vec.iter().map(|g| g.greet()).collect();
+        assert_eq!(greetings, ["hello", "hi, there"]);
+    }
+}
@@ -0,0 +1,14 @@
+//! Requires nightly: `Allocator`, `Unsize`, and `ptr::Pointee`/`from_raw_parts`
+//! have no stable equivalents yet.
+#![feature(allocator_api, unsize, ptr_metadata, slice_from_ptr_range)]
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod binary_heap;
+pub mod dyn_vec;
+pub mod slice;
+pub mod vector;
@@ -1,4 +1,4 @@
-use std::{marker::PhantomData, ops::Range, ptr::NonNull};
+use core::{marker::PhantomData, ops::Range, ptr::NonNull};
 
 pub trait AsSlice<T: Sized> {
     /// required method
@@ -69,7 +69,7 @@ impl<T> SlicePtr<T> {
     }
     pub unsafe fn range(self, range: Range<usize>) -> Self {
         assert!(range.start <= range.end && range.end <= self.len);
-        if std::mem::size_of::<T>() == 0 {
+        if core::mem::size_of::<T>() == 0 {
             self
         } else {
             Self {
@@ -128,7 +128,7 @@ impl<T> SlicePtr<T> {
         let mut right = unsafe { self.head.add(self.len - 1) };
         while left < right {
             unsafe {
-                std::ptr::swap(left.as_ptr(), right.as_ptr());
+                core::ptr::swap(left.as_ptr(), right.as_ptr());
                 left = left.add(1);
                 right = right.sub(1);
             }
@@ -136,7 +136,7 @@ impl<T> SlicePtr<T> {
     }
     pub fn sort_by<F>(self, compare: F)
     where
-        F: Fn(&T, &T) -> std::cmp::Ordering + Copy,
+        F: Fn(&T, &T) -> core::cmp::Ordering + Copy,
     {
         unimplemented!()
     }
@@ -1,8 +1,10 @@
 extern crate alloc;
 use alloc::alloc::{AllocError, Allocator, Global, Layout, LayoutError};
+#[cfg(feature = "std")]
 use core::fmt::Display;
+use core::mem::MaybeUninit;
+use core::ops::{Bound, Index, IndexMut, Range, RangeBounds, RangeFull};
 use core::{marker::PhantomData, ptr::NonNull};
-use std::ops::{Index, IndexMut, Range, RangeFull};
 
 #[derive(Debug)]
 pub enum VectorError {
@@ -10,8 +12,9 @@ pub enum VectorError {
     AllocError(AllocError),
 }
 
+#[cfg(feature = "std")]
 impl Display for VectorError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             VectorError::LayoutError(e) => write!(f, "Layout error: {}", e),
             VectorError::AllocError(e) => write!(f, "Allocation error: {}", e),
@@ -30,29 +33,43 @@ impl From<AllocError> for VectorError {
     }
 }
 
-pub struct Vector<T> {
+pub struct Vector<T, A: Allocator = Global> {
     buffer: NonNull<T>,
     len: usize,
     capacity: usize,
-    allocator: Global,
+    allocator: A,
     _marker: PhantomData<T>,
 }
 
-impl<T> Vector<T> {
+impl<T> Vector<T, Global> {
     pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+    pub fn with_capacity(capacity: usize) -> Result<Self, VectorError> {
+        Self::with_capacity_in(capacity, Global)
+    }
+    /// Named `try_from_iter`, not `from_iter`, since this can fail.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, VectorError> {
+        let mut vector = Self::new();
+        vector.extend(iter)?;
+        Ok(vector)
+    }
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    pub fn new_in(alloc: A) -> Self {
         Self {
             buffer: NonNull::dangling(),
             len: 0,
             capacity: 0,
-            allocator: Global,
+            allocator: alloc,
             _marker: PhantomData,
         }
     }
-    pub fn with_capacity(capacity: usize) -> Result<Self, VectorError> {
-        let allocator = Global;
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Result<Self, VectorError> {
         let layout = Layout::array::<T>(capacity)?;
         let buffer = if layout.size() > 0 {
-            allocator.allocate(layout)?.cast()
+            alloc.allocate(layout)?.cast()
         } else {
             NonNull::dangling()
         };
@@ -60,10 +77,13 @@ impl<T> Vector<T> {
             buffer,
             len: 0,
             capacity,
-            allocator,
+            allocator: alloc,
             _marker: PhantomData,
         })
     }
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
     pub fn reserve(&mut self, additional: usize) -> Result<(), VectorError> {
         if self.len + additional > self.capacity {
             let old_layout = Layout::array::<T>(self.capacity)?;
@@ -103,17 +123,40 @@ impl<T> Vector<T> {
     pub fn push(&mut self, value: T) -> Result<(), VectorError> {
         self.reserve(1)?;
         unsafe {
-            std::ptr::write(self.buffer.as_ptr().add(self.len()), value);
+            core::ptr::write(self.buffer.as_ptr().add(self.len()), value);
         }
         self.len += 1;
         Ok(())
     }
+    /// Reserves from `size_hint` up front instead of re-checking on every push.
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), VectorError> {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower + 1)?;
+        for value in iter {
+            if self.len == self.capacity {
+                self.reserve(1)?;
+            }
+            self.spare_capacity_mut()[0].write(value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+    /// The uninitialized tail of the buffer, for writing before bumping `len`.
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.buffer.as_ptr().add(self.len).cast::<MaybeUninit<T>>(),
+                self.capacity - self.len,
+            )
+        }
+    }
     pub fn pop(&mut self) -> Option<T> {
         if self.len == 0 {
             None
         } else {
             self.len -= 1;
-            unsafe { Some(std::ptr::read(self.buffer.as_ptr().add(self.len()))) }
+            unsafe { Some(core::ptr::read(self.buffer.as_ptr().add(self.len()))) }
         }
     }
     pub fn get(&self, index: usize) -> Option<&T> {
@@ -161,15 +204,179 @@ impl<T> Vector<T> {
         let cmp_f = |t1: &T, t2: &T| f(t1).cmp(f(t2));
         self.sort_by(cmp_f);
     }
+
+    fn resolve_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end && end <= self.len, "range out of bounds");
+        (start, end)
+    }
+
+    /// Removes `range`, shifting the tail left to close the gap.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+        let len = self.len;
+        let (start, end) = self.resolve_range(range);
+
+        let full = RawVecPtrRange::from(&*self);
+        let iter = unsafe { full.range(start..end) };
+
+        // Shrink len to `start` up front so a leaked `Drain` can't expose or
+        // double-drop the elements being removed.
+        self.len = start;
+
+        Drain {
+            vector: NonNull::from(&mut *self),
+            iter,
+            tail_start: end,
+            tail_len: len - end,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Removes `range` and inserts `replace_with` in its place, reserving
+    /// for the size delta once.
+    pub fn splice<R: RangeBounds<usize>, I: IntoIterator<Item = T>>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Vector<T, A>, VectorError>
+    where
+        A: Clone,
+    {
+        let len = self.len;
+        let (start, end) = self.resolve_range(range);
+        let removed_len = end - start;
+
+        // Every fallible allocation happens before anything is read out of
+        // self's buffer, so a failure here leaves self untouched.
+        let mut removed = Vector::with_capacity_in(removed_len, self.allocator.clone())?;
+
+        let mut replacement = Vector::new_in(self.allocator.clone());
+        replacement.extend(replace_with)?;
+        let inserted_len = replacement.len();
+        let new_len = start + inserted_len + (len - end);
+        if new_len > len {
+            self.reserve(new_len - len)?;
+        }
+
+        for i in start..end {
+            let value = unsafe { core::ptr::read(self.buffer.as_ptr().add(i)) };
+            removed.push(value).unwrap();
+        }
+
+        unsafe {
+            let tail_len = len - end;
+            if tail_len > 0 {
+                let src = self.buffer.as_ptr().add(end);
+                let dst = self.buffer.as_ptr().add(start + inserted_len);
+                core::ptr::copy(src, dst, tail_len);
+            }
+            core::ptr::copy_nonoverlapping(
+                replacement.buffer.as_ptr().cast_const(),
+                self.buffer.as_ptr().add(start),
+                inserted_len,
+            );
+        }
+        // The replacement's elements were moved out byte-for-byte above; zero
+        // its len so dropping it only frees its allocation, not the elements.
+        replacement.len = 0;
+        self.len = new_len;
+
+        Ok(removed)
+    }
+
+    /// Inserts `value` at `index`, shifting everything at and after it one
+    /// slot to the right.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), VectorError> {
+        assert!(index <= self.len, "insertion index out of bounds");
+        self.reserve(1)?;
+        unsafe {
+            let tail_len = self.len - index;
+            if tail_len > 0 {
+                let src = self.buffer.as_ptr().add(index);
+                let dst = self.buffer.as_ptr().add(index + 1);
+                core::ptr::copy(src, dst, tail_len);
+            }
+            core::ptr::write(self.buffer.as_ptr().add(index), value);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting everything after
+    /// it one slot to the left.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index out of bounds");
+        unsafe {
+            let value = core::ptr::read(self.buffer.as_ptr().add(index));
+            let tail_len = self.len - index - 1;
+            if tail_len > 0 {
+                let src = self.buffer.as_ptr().add(index + 1);
+                let dst = self.buffer.as_ptr().add(index);
+                core::ptr::copy(src, dst, tail_len);
+            }
+            self.len -= 1;
+            value
+        }
+    }
+
+    /// Binary searches a `Vector` sorted by `f`; `Ok(index)` on an exact
+    /// match, `Err(insertion_point)` otherwise.
+    pub fn binary_search_by<F: Fn(&T) -> core::cmp::Ordering>(&self, f: F) -> Result<usize, usize> {
+        let mut lo = 0;
+        let mut hi = self.len;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match f(&self[mid]) {
+                core::cmp::Ordering::Equal => return Ok(mid),
+                core::cmp::Ordering::Less => lo = mid + 1,
+                core::cmp::Ordering::Greater => hi = mid,
+            }
+        }
+        Err(lo)
+    }
+    pub fn binary_search_by_key<K: Ord, F: Fn(&T) -> K>(
+        &self,
+        key: &K,
+        f: F,
+    ) -> Result<usize, usize> {
+        self.binary_search_by(|probe| f(probe).cmp(key))
+    }
+    /// Returns the first index for which `pred` turns false, assuming `pred`
+    /// is true for a prefix of the (sorted) `Vector` and false afterward.
+    pub fn partition_point<P: Fn(&T) -> bool>(&self, pred: P) -> usize {
+        let mut lo = 0;
+        let mut hi = self.len;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(&self[mid]) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
 }
 
-impl<T: Ord> Vector<T> {
+impl<T: Ord, A: Allocator> Vector<T, A> {
     pub fn sort(&mut self) {
         self.sort_by(Ord::cmp);
     }
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        self.binary_search_by(|probe| probe.cmp(x))
+    }
 }
 
-impl<T> Drop for Vector<T> {
+impl<T, A: Allocator> Drop for Vector<T, A> {
     fn drop(&mut self) {
         while let Some(_) = self.pop() {}
         if self.capacity > 0 {
@@ -181,29 +388,29 @@ impl<T> Drop for Vector<T> {
     }
 }
 
-pub struct VectorIter<T> {
-    _vector: Vector<T>,
+pub struct VectorIter<T, A: Allocator = Global> {
+    _vector: Vector<T, A>,
     iter: RawVecPtrRange<T>,
 }
-impl<T> Iterator for VectorIter<T> {
+impl<T, A: Allocator> Iterator for VectorIter<T, A> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|ptr| unsafe { ptr.read() })
     }
 }
-impl<T> DoubleEndedIterator for VectorIter<T> {
+impl<T, A: Allocator> DoubleEndedIterator for VectorIter<T, A> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back().map(|ptr| unsafe { ptr.read() })
     }
 }
-impl<T> ExactSizeIterator for VectorIter<T> {
+impl<T, A: Allocator> ExactSizeIterator for VectorIter<T, A> {
     fn len(&self) -> usize {
         self._vector.len
     }
 }
-impl<T> IntoIterator for Vector<T> {
+impl<T, A: Allocator> IntoIterator for Vector<T, A> {
     type Item = T;
-    type IntoIter = VectorIter<T>;
+    type IntoIter = VectorIter<T, A>;
     fn into_iter(self) -> Self::IntoIter {
         VectorIter {
             iter: RawVecPtrRange::from(&self),
@@ -211,9 +418,9 @@ impl<T> IntoIterator for Vector<T> {
         }
     }
 }
-impl<T: Clone> Clone for Vector<T> {
+impl<T: Clone, A: Allocator + Clone> Clone for Vector<T, A> {
     fn clone(&self) -> Self {
-        let mut new_vector = Self::with_capacity(self.len()).unwrap();
+        let mut new_vector = Self::with_capacity_in(self.len(), self.allocator.clone()).unwrap();
         for item in self.iter() {
             new_vector.push(item.clone()).unwrap();
         }
@@ -221,6 +428,44 @@ impl<T: Clone> Clone for Vector<T> {
     }
 }
 
+pub struct Drain<'a, T, A: Allocator = Global> {
+    vector: NonNull<Vector<T, A>>,
+    iter: RawVecPtrRange<T>,
+    tail_start: usize,
+    tail_len: usize,
+    _marker: PhantomData<&'a mut Vector<T, A>>,
+}
+impl<'a, T, A: Allocator> Iterator for Drain<'a, T, A> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|ptr| unsafe { ptr.read() })
+    }
+}
+impl<'a, T, A: Allocator> DoubleEndedIterator for Drain<'a, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|ptr| unsafe { ptr.read() })
+    }
+}
+impl<'a, T, A: Allocator> Drop for Drain<'a, T, A> {
+    fn drop(&mut self) {
+        // Drop whatever the caller didn't consume.
+        for ptr in self.iter.by_ref() {
+            unsafe { core::ptr::drop_in_place(ptr.as_ptr()) };
+        }
+        // Close the gap by shifting the surviving tail left, once.
+        if self.tail_len > 0 {
+            unsafe {
+                let vector = self.vector.as_mut();
+                let start = vector.len;
+                let src = vector.buffer.as_ptr().add(self.tail_start);
+                let dst = vector.buffer.as_ptr().add(start);
+                core::ptr::copy(src, dst, self.tail_len);
+                vector.len = start + self.tail_len;
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 struct RawVecPtrRange<T> {
     head: NonNull<T>,
@@ -231,7 +476,7 @@ impl<T> RawVecPtrRange<T> {
         unsafe {
             Self {
                 head: self.head.add(range.start),
-                tail: self.tail.add(range.end),
+                tail: self.head.add(range.end),
             }
         }
     }
@@ -272,24 +517,134 @@ impl<T> RawVecPtrRange<T> {
             }
         }
     }
+    /// Introsort: bounds worst-case stack depth to O(log n) via a heapsort
+    /// fallback, unlike a naive last-element-pivot quicksort.
     pub fn quick_sort_by<F: Fn(&T, &T) -> core::cmp::Ordering + Copy>(self, f: F) {
         let len = self.len();
         if len <= 1 {
             return;
         }
-        let pivot_index = len - 1;
-        let pivot = &self[pivot_index];
-        let mut i = 0;
-        for j in 0..pivot_index {
-            if f(&self[j], pivot) == core::cmp::Ordering::Less {
-                self.swap(i, j);
-                i += 1;
+        let depth_limit = 2 * Self::log2_floor(len);
+        self.introsort_by(depth_limit, f);
+    }
+
+    fn log2_floor(n: usize) -> usize {
+        (usize::BITS - 1 - n.leading_zeros()) as usize
+    }
+
+    fn introsort_by<F: Fn(&T, &T) -> core::cmp::Ordering + Copy>(
+        mut self,
+        mut depth_limit: usize,
+        f: F,
+    ) {
+        const INSERTION_SORT_THRESHOLD: usize = 20;
+        loop {
+            let len = self.len();
+            if len <= INSERTION_SORT_THRESHOLD {
+                self.insertion_sort_by(f);
+                return;
+            }
+            if depth_limit == 0 {
+                self.heap_sort_by(f);
+                return;
+            }
+            depth_limit -= 1;
+
+            let pivot_index = self.median_of_three_index(f);
+            let last = len - 1;
+            self.swap(pivot_index, last);
+            let pivot = &self[last];
+            let mut i = 0;
+            for j in 0..last {
+                if f(&self[j], pivot) == core::cmp::Ordering::Less {
+                    self.swap(i, j);
+                    i += 1;
+                }
+            }
+            self.swap(i, last);
+            let (left, right) = unsafe { self.split(i) };
+
+            // Recurse into the smaller partition and loop on the larger one
+            // so stack depth stays O(log n) regardless of input order.
+            if left.len() < right.len() {
+                left.introsort_by(depth_limit, f);
+                self = right;
+            } else {
+                right.introsort_by(depth_limit, f);
+                self = left;
+            }
+        }
+    }
+
+    fn median_of_three_index<F: Fn(&T, &T) -> core::cmp::Ordering + Copy>(self, f: F) -> usize {
+        let len = self.len();
+        let (a, b, c) = (0, len / 2, len - 1);
+        let (x, y, z) = (&self[a], &self[b], &self[c]);
+        if f(x, y) == core::cmp::Ordering::Less {
+            if f(y, z) == core::cmp::Ordering::Less {
+                b
+            } else if f(x, z) == core::cmp::Ordering::Less {
+                c
+            } else {
+                a
+            }
+        } else if f(x, z) == core::cmp::Ordering::Less {
+            a
+        } else if f(y, z) == core::cmp::Ordering::Less {
+            c
+        } else {
+            b
+        }
+    }
+
+    fn insertion_sort_by<F: Fn(&T, &T) -> core::cmp::Ordering + Copy>(self, f: F) {
+        let len = self.len();
+        for i in 1..len {
+            let mut j = i;
+            while j > 0 && f(&self[j - 1], &self[j]) == core::cmp::Ordering::Greater {
+                self.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    fn heap_sort_by<F: Fn(&T, &T) -> core::cmp::Ordering + Copy>(self, f: F) {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+        for start in (0..len / 2).rev() {
+            self.sift_down_by(start, len, f);
+        }
+        for end in (1..len).rev() {
+            self.swap(0, end);
+            self.sift_down_by(0, end, f);
+        }
+    }
+
+    fn sift_down_by<F: Fn(&T, &T) -> core::cmp::Ordering + Copy>(
+        self,
+        mut root: usize,
+        len: usize,
+        f: F,
+    ) {
+        loop {
+            let left = 2 * root + 1;
+            if left >= len {
+                break;
+            }
+            let right = left + 1;
+            let mut largest = left;
+            if right < len && f(&self[right], &self[largest]) == core::cmp::Ordering::Greater {
+                largest = right;
+            }
+            if f(&self[largest], &self[root]) == core::cmp::Ordering::Greater {
+                self.swap(root, largest);
+                root = largest;
+            } else {
+                break;
             }
         }
-        self.swap(i, pivot_index);
-        let (left, right) = unsafe { self.split(i) };
-        left.quick_sort_by(f);
-        right.quick_sort_by(f);
     }
 }
 impl<T> Clone for RawVecPtrRange<T> {
@@ -328,8 +683,8 @@ impl<T> ExactSizeIterator for RawVecPtrRange<T> {
         unsafe { self.tail.offset_from(self.head) }.abs() as usize
     }
 }
-impl<T> From<&Vector<T>> for RawVecPtrRange<T> {
-    fn from(value: &Vector<T>) -> Self {
+impl<T, A: Allocator> From<&Vector<T, A>> for RawVecPtrRange<T> {
+    fn from(value: &Vector<T, A>) -> Self {
         RawVecPtrRange {
             head: value.buffer,
             tail: unsafe { value.buffer.add(value.len) },
@@ -404,7 +759,7 @@ impl<'a, T> From<RawVecPtrRange<T>> for VecRefIter<'a, T> {
         }
     }
 }
-impl<'a, T> IntoIterator for &'a Vector<T> {
+impl<'a, T, A: Allocator> IntoIterator for &'a Vector<T, A> {
     type IntoIter = VecRefIter<'a, T>;
     type Item = &'a T;
     fn into_iter(self) -> Self::IntoIter {
@@ -440,7 +795,7 @@ impl<'a, T> From<RawVecPtrRange<T>> for VecMutIter<'a, T> {
         }
     }
 }
-impl<'a, T> IntoIterator for &'a mut Vector<T> {
+impl<'a, T, A: Allocator> IntoIterator for &'a mut Vector<T, A> {
     type Item = &'a mut T;
     type IntoIter = VecMutIter<'a, T>;
     fn into_iter(self) -> Self::IntoIter {
@@ -448,18 +803,18 @@ impl<'a, T> IntoIterator for &'a mut Vector<T> {
     }
 }
 
-impl<T> Index<usize> for Vector<T> {
+impl<T, A: Allocator> Index<usize> for Vector<T, A> {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
         self.get(index).expect("out bound of index")
     }
 }
-impl<T> IndexMut<usize> for Vector<T> {
+impl<T, A: Allocator> IndexMut<usize> for Vector<T, A> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         self.get_mut(index).expect("out bound of index")
     }
 }
-impl<T> Index<Range<usize>> for Vector<T> {
+impl<T, A: Allocator> Index<Range<usize>> for Vector<T, A> {
     type Output = [T];
     fn index(&self, index: Range<usize>) -> &[T] {
         if index.start >= self.len || index.end > self.len {
@@ -472,7 +827,7 @@ impl<T> Index<Range<usize>> for Vector<T> {
         }
     }
 }
-impl<T> IndexMut<Range<usize>> for Vector<T> {
+impl<T, A: Allocator> IndexMut<Range<usize>> for Vector<T, A> {
     fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
         if index.start >= self.len || index.end > self.len {
             panic!("index out of bound!");
@@ -484,14 +839,14 @@ impl<T> IndexMut<Range<usize>> for Vector<T> {
         }
     }
 }
-impl<T> Index<RangeFull> for Vector<T> {
+impl<T, A: Allocator> Index<RangeFull> for Vector<T, A> {
     type Output = [T];
     fn index(&self, index: RangeFull) -> &Self::Output {
         let _ = index;
         self.as_slice()
     }
 }
-impl<T> IndexMut<RangeFull> for Vector<T> {
+impl<T, A: Allocator> IndexMut<RangeFull> for Vector<T, A> {
     fn index_mut(&mut self, index: RangeFull) -> &mut Self::Output {
         let _ = index;
         self.as_slice_mut()
@@ -530,7 +885,6 @@ mod tests {
         assert_eq!(vec.len(), 10);
         *vec.get_mut(2).unwrap() = 42;
         assert_eq!(vec.get(2).unwrap(), &42);
-        // assert_eq!(vec.binary_search(&42), Some(2));
     }
     #[test]
     fn test_vector_iter() {
@@ -575,4 +929,163 @@ mod tests {
             println!("{}", i);
         }
     }
+    #[test]
+    fn test_vector_extend() {
+        let mut vec = vector![1, 2, 3];
+        vec.extend(4..=6).unwrap();
+        assert_eq!(vec.len(), 6);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    }
+    #[test]
+    fn test_vector_from_iter() {
+        let vec = Vector::try_from_iter(0..10).unwrap();
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+    #[test]
+    fn test_vector_new_in() {
+        let mut vec: Vector<i32, Global> = Vector::new_in(Global);
+        vec.push(1).unwrap();
+        vec.push(2).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2]);
+        assert!(core::ptr::eq(vec.allocator(), vec.allocator()));
+    }
+    #[test]
+    fn test_vector_with_capacity_in() {
+        let mut vec = Vector::with_capacity_in(4, Global).unwrap();
+        vec.push(1).unwrap();
+        assert_eq!(vec.as_slice(), &[1]);
+    }
+    #[test]
+    fn test_vector_drain() {
+        let mut vec = vector![1, 2, 3, 4, 5];
+        let drained: alloc::vec::Vec<_> = vec.drain(1..3).collect();
+        assert_eq!(drained, [2, 3]);
+        assert_eq!(vec.as_slice(), &[1, 4, 5]);
+    }
+    #[test]
+    fn test_vector_drain_dropped_early_still_closes_gap() {
+        let mut vec = vector![1, 2, 3, 4, 5];
+        vec.drain(1..3);
+        assert_eq!(vec.as_slice(), &[1, 4, 5]);
+    }
+    #[test]
+    fn test_vector_splice() {
+        let mut vec = vector![1, 2, 3, 4, 5];
+        let removed = vec.splice(1..3, [10, 20, 30]).unwrap();
+        assert_eq!(removed.as_slice(), &[2, 3]);
+        assert_eq!(vec.as_slice(), &[1, 10, 20, 30, 4, 5]);
+    }
+
+    /// An allocator that fails its `N`th allocation (counted from the last
+    /// `reset()`), used to exercise `splice`'s allocation-failure path.
+    #[derive(Clone)]
+    struct FailAfter {
+        calls: alloc::rc::Rc<core::cell::Cell<usize>>,
+        fail_at: usize,
+    }
+    impl FailAfter {
+        fn new(fail_at: usize) -> Self {
+            Self {
+                calls: alloc::rc::Rc::new(core::cell::Cell::new(0)),
+                fail_at,
+            }
+        }
+        fn reset(&self) {
+            self.calls.set(0);
+        }
+    }
+    unsafe impl Allocator for FailAfter {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let n = self.calls.get() + 1;
+            self.calls.set(n);
+            if n == self.fail_at {
+                return Err(AllocError);
+            }
+            Global.allocate(layout)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    #[test]
+    fn test_vector_splice_leaves_self_untouched_on_allocation_failure() {
+        let drops = alloc::rc::Rc::new(core::cell::Cell::new(0));
+        struct Counted(alloc::rc::Rc<core::cell::Cell<usize>>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let alloc = FailAfter::new(2);
+        let mut vec = Vector::with_capacity_in(3, alloc.clone()).unwrap();
+        for _ in 0..3 {
+            vec.push(Counted(drops.clone())).unwrap();
+        }
+
+        // `removed`'s with_capacity_in is the 1st allocation (succeeds);
+        // `replacement`'s extend triggers the 2nd (fails).
+        alloc.reset();
+        let result = vec.splice(1..2, [Counted(drops.clone())]);
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 3);
+
+        drop(result);
+        drop(vec);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn test_vector_insert_remove() {
+        let mut vec = vector![1, 2, 4];
+        vec.insert(2, 3).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(vec.remove(0), 1);
+        assert_eq!(vec.as_slice(), &[2, 3, 4]);
+    }
+    #[test]
+    fn test_vector_binary_search() {
+        let vec = vector![1, 3, 5, 7, 9, 11];
+        assert_eq!(vec.binary_search(&7), Ok(3));
+        assert_eq!(vec.binary_search(&4), Err(2));
+        assert_eq!(vec.binary_search(&0), Err(0));
+        assert_eq!(vec.binary_search(&12), Err(6));
+    }
+    #[test]
+    fn test_vector_binary_search_by_key() {
+        let vec = vector![(1, "a"), (3, "b"), (5, "c")];
+        assert_eq!(vec.binary_search_by_key(&3, |&(k, _)| k), Ok(1));
+        assert_eq!(vec.binary_search_by_key(&4, |&(k, _)| k), Err(2));
+    }
+    #[test]
+    fn test_vector_partition_point() {
+        let vec = vector![1, 2, 3, 4, 5, 6];
+        assert_eq!(vec.partition_point(|&x| x < 4), 3);
+    }
+    #[test]
+    fn test_vector_sort_large_sorted_no_overflow() {
+        let mut vec = Vector::new();
+        for i in 0..100_000 {
+            vec.push(i).unwrap();
+        }
+        vec.sort();
+        assert_eq!(vec.len(), 100_000);
+        for i in 0..100_000 {
+            assert_eq!(*vec.get(i).unwrap(), i);
+        }
+    }
+    #[test]
+    fn test_vector_sort_large_reverse_sorted_no_overflow() {
+        let mut vec = Vector::new();
+        for i in (0..100_000).rev() {
+            vec.push(i).unwrap();
+        }
+        vec.sort();
+        assert_eq!(vec.len(), 100_000);
+        for i in 0..100_000 {
+            assert_eq!(*vec.get(i).unwrap(), i);
+        }
+    }
 }